@@ -5,6 +5,7 @@
 // - Adding a Scallop program
 // - Running the program
 // - Querying results
+// - Counting over a recursive relation
 
 use scallop_core::integrate::*;
 use scallop_core::runtime::provenance::unit::UnitProvenance;
@@ -32,8 +33,12 @@ fn main() -> Result<(), IntegrateError> {
         rel path(a, b) = edge(a, b)
         rel path(a, c) = path(a, b), edge(b, c)
 
-        // Query the path relation
+        // Count the nodes reachable from each node
+        rel reach_count(n, c) = c := count(m: path(n, m))
+
+        // Query the path and reach_count relations
         query path
+        query reach_count
     "#)?;
 
     println!("Program loaded successfully");
@@ -62,6 +67,24 @@ fn main() -> Result<(), IntegrateError> {
         }
     }
 
+    // The count runs after path has been fully computed; sort the rows,
+    // since iteration order is unspecified
+    let mut counts: Vec<(i32, usize)> = Vec::new();
+    for elem in ctx.computed_relation_ref("reach_count").unwrap().iter() {
+        let tuple = &elem.1;
+        if let (Some(Value::I32(node)), Some(Value::USize(count))) =
+            (tuple[0].get_value(), tuple[1].get_value())
+        {
+            counts.push((*node, *count));
+        }
+    }
+    counts.sort();
+
+    println!("\nResults for reach_count relation:");
+    for (node, count) in counts {
+        println!("  reach_count({}, {})", node, count);
+    }
+
     println!("\n=== Example Complete ===");
 
     Ok(())
@@ -75,7 +75,8 @@ impl ForeignFunction for StringUppercase {
 }
 
 // Foreign Function 3: Integer Absolute Value (custom implementation)
-// Note: Renamed to avoid potential conflict with stdlib abs
+// Note: Renamed because a name maps to one function; the stdlib `$abs<T: Number>`
+// already covers i32 and f64 as a single generic function
 #[derive(Clone)]
 pub struct IntAbs;
 